@@ -10,13 +10,185 @@
 /// A node will never be included twice in the path as determined by the `Eq` relationship.
 ///
 
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
 use std::fmt::Debug;
+use std::hash::Hash;
 
 use num_traits::Zero;
 use rand::Rng;
 
 
+/// Search backend selectable through [`dstar_with_backend`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Backend {
+    /// The recursive IDA*-style search — [`dstar`]'s default behavior.
+    Ida,
+    /// [`fringe`] search: an explicit `now`/`later` frontier instead of IDA*'s recursion, so
+    /// raising the bound re-walks the fringe rather than re-expanding the whole tree.
+    Fringe,
+}
+
 pub fn dstar<N, C, FN, IN, FH, FS>(
+    start: &N,
+    successors: FN,
+    h: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Clone + Debug + Hash,
+    C: Zero + Ord + Copy + Debug,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    dstar_with_backend(start, successors, h, success, Backend::Ida)
+}
+
+/// Like [`dstar`], but with the search backend picked explicitly instead of always using IDA*.
+pub fn dstar_with_backend<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut h: FH,
+    mut success: FS,
+    backend: Backend,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Clone + Debug + Hash,
+    C: Zero + Ord + Copy + Debug,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    match backend {
+        Backend::Fringe => fringe(start, successors, h, success),
+        Backend::Ida => {
+            let mut bound = h(start);
+            let mut path = vec![start.clone()]; //vector containing path to the goal starting from the start location
+            loop {
+                match compute_shortest_path(
+                    &mut path,
+                    Zero::zero(), //Cost to reach the node from the start node
+                    bound,
+                    &mut successors,
+                    &mut h, // Heuristic cost from the node to the GOALLOC node
+                    &mut success,
+                ) {
+                    //ComputeShortestPathes for a path to the goal location, if found returns the coordinate and cost
+                    Path::Found(path, cost) => return Some((path, cost)),
+                    Path::MinimumPath(min) => {
+                        if bound == min {
+                            return None;
+                        }
+                        bound = min;
+                    }
+                    Path::NoTraversal => return None,
+                }
+            }
+        }
+    }
+}
+
+enum Path<N, C> {
+    Found(Vec<N>, C),
+    MinimumPath(C),
+    NoTraversal,
+}
+//computes the shortest path and dynamically change the cost of each edges to account for changing obstacle or changing environemnt
+fn compute_shortest_path<N, C, FN, IN, FH, FS>(
+    path: &mut Vec<N>,
+    cost: C,
+    bound: C,
+    successors: &mut FN,
+    h: &mut FH,
+    success: &mut FS,
+) -> Path<N, C>
+where
+    N: Eq + Clone + Debug,
+    C: Zero + Ord + Copy + Debug,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let neighbours = {
+        let start = &path[path.len() - 1];
+        let f = cost + h(start);
+        if f > bound {
+            return Path::MinimumPath(f);
+        }
+        if success(start) {
+            return Path::Found(path.clone(), f);
+        }
+        let mut neighbours = successors(start)
+            .into_iter()
+            .filter_map(|(n, c)| {
+                (!path.contains(&n)).then(|| {
+                    let h = h(&n);
+                    (n, c, c + h)
+                })
+            })
+            .collect::<Vec<_>>();
+        neighbours.sort_unstable_by(|(_, _, c1), (_, _, c2)| c1.cmp(c2));
+        neighbours
+    };
+    let mut min = None;
+    for (node, extra, _) in neighbours {
+        path.push(node);
+        match compute_shortest_path(path, cost + extra, bound, successors, h, success) {
+            found_path @ Path::Found(_, _) => return found_path,
+            Path::MinimumPath(m) => match min {
+                None => min = Some(m),
+                Some(n) if m < n => min = Some(m),
+                Some(_) => (),
+            },
+            Path::NoTraversal => (),
+        }
+        path.pop();
+    }
+    min.map_or(Path::NoTraversal, Path::MinimumPath)
+}
+
+/// A successor candidate buffered in [`compute_shortest_path_lazy`]'s bounded frontier, ordered
+/// by `total` (`extra + heuristic`) so the cheapest-looking one is explored first.
+struct LazyCandidate<N, C> {
+    total: C,
+    extra: C,
+    node: N,
+}
+
+impl<N, C: PartialEq> PartialEq for LazyCandidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.total == other.total
+    }
+}
+
+impl<N, C: PartialEq> Eq for LazyCandidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for LazyCandidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for LazyCandidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total.cmp(&other.total)
+    }
+}
+
+/// Like [`dstar`], but `successors` is pulled from one item at a time instead of being drained
+/// into a `Vec` and sorted up front like [`compute_shortest_path`] does. At most
+/// [`LAZY_LOOKAHEAD`] candidates are buffered in a small `BinaryHeap` at any point, so a caller
+/// whose `successors` closure is expensive (a database lookup, heavy geometry) only pays to
+/// generate the next candidate once the buffer has room for it — and pulling stops the moment a
+/// descendant reports [`Path::Found`], since nothing further at this node is needed once that
+/// happens. Every successor that *is* pulled is still eventually explored (buffering only
+/// reorders them best-first; it never drops one), so this remains as complete as
+/// [`compute_shortest_path`].
+pub fn dstar_lazy<N, C, FN, IN, FH, FS>(
     start: &N,
     mut successors: FN,
     mut h: FH,
@@ -31,42 +203,151 @@ where
     FS: FnMut(&N) -> bool,
 {
     let mut bound = h(start);
-    let mut path = vec![start.clone()]; //vector containing path to the goal starting from the start location
+    let mut path = vec![start.clone()];
     loop {
-        match compute_shortest_path(
+        match compute_shortest_path_lazy(&mut path, Zero::zero(), bound, &mut successors, &mut h, &mut success) {
+            Path::Found(path, cost) => return Some((path, cost)),
+            Path::MinimumPath(min) => {
+                if bound == min {
+                    return None;
+                }
+                bound = min;
+            }
+            Path::NoTraversal => return None,
+        }
+    }
+}
+
+/// How many pulled-but-not-yet-explored successors [`compute_shortest_path_lazy`] buffers before
+/// committing to one. Keeping this small is the point: it bounds how far ahead of the current
+/// exploration the lazy pull ever has to run.
+const LAZY_LOOKAHEAD: usize = 4;
+
+fn compute_shortest_path_lazy<N, C, FN, IN, FH, FS>(
+    path: &mut Vec<N>,
+    cost: C,
+    bound: C,
+    successors: &mut FN,
+    h: &mut FH,
+    success: &mut FS,
+) -> Path<N, C>
+where
+    N: Eq + Clone + Debug,
+    C: Zero + Ord + Copy + Debug,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut pending = {
+        let start = &path[path.len() - 1];
+        let f = cost + h(start);
+        if f > bound {
+            return Path::MinimumPath(f);
+        }
+        if success(start) {
+            return Path::Found(path.clone(), f);
+        }
+        successors(start).into_iter()
+    };
+
+    // Top the frontier up to `LAZY_LOOKAHEAD` candidates, pull the best of those, recurse, and
+    // repeat — so `successors` is never asked for more than `LAZY_LOOKAHEAD` items beyond what's
+    // already been explored, and pulling stops for good the moment a deeper call finds the goal.
+    let mut frontier: BinaryHeap<Reverse<LazyCandidate<N, C>>> = BinaryHeap::new();
+    let mut min = None;
+    loop {
+        while frontier.len() < LAZY_LOOKAHEAD {
+            match pending.next() {
+                Some((n, extra)) => {
+                    if path.contains(&n) {
+                        continue;
+                    }
+                    let total = extra + h(&n);
+                    frontier.push(Reverse(LazyCandidate { total, extra, node: n }));
+                }
+                None => break,
+            }
+        }
+        let Reverse(LazyCandidate { extra, node, .. }) = match frontier.pop() {
+            Some(candidate) => candidate,
+            None => break,
+        };
+        path.push(node);
+        match compute_shortest_path_lazy(path, cost + extra, bound, successors, h, success) {
+            found_path @ Path::Found(_, _) => return found_path,
+            Path::MinimumPath(m) => match min {
+                None => min = Some(m),
+                Some(n) if m < n => min = Some(m),
+                Some(_) => (),
+            },
+            Path::NoTraversal => (),
+        }
+        path.pop();
+    }
+    min.map_or(Path::NoTraversal, Path::MinimumPath)
+}
+
+/// Whether [`dstar_or_closest`] reached the goal or only the closest reachable node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Reached {
+    Complete,
+    Partial,
+}
+
+/// Like [`dstar`], but when the goal cannot be reached it still returns the best progress made:
+/// the path to the expanded node with the smallest heuristic-to-goal seen during the search
+/// (ties broken by the lowest cost to reach it), tagged [`Reached::Partial`]. This is useful for
+/// robots that should keep moving toward the goal rather than stop on no path at all.
+pub fn dstar_or_closest<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut h: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C, Reached)>
+where
+    N: Eq + Clone + Debug,
+    C: Zero + Ord + Copy + Debug,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut bound = h(start);
+    let mut path = vec![start.clone()];
+    let mut closest: Option<(C, C, Vec<N>)> = None; // (heuristic, cost, path prefix)
+    loop {
+        match compute_shortest_path_or_closest(
             &mut path,
-            Zero::zero(), //Cost to reach the node from the start node
+            Zero::zero(),
             bound,
             &mut successors,
-            &mut h, // Heuristic cost from the node to the GOALLOC node
+            &mut h,
             &mut success,
-        ) { 
-            //ComputeShortestPathes for a path to the goal location, if found returns the coordinate and cost
-            Path::Found(path, cost) => return Some((path, cost)), 
+            &mut closest,
+        ) {
+            Path::Found(path, cost) => return Some((path, cost, Reached::Complete)),
             Path::MinimumPath(min) => {
                 if bound == min {
-                    return None;
+                    break;
                 }
                 bound = min;
             }
-            Path::NoTraversal => return None,
+            Path::NoTraversal => break,
         }
     }
+    closest.map(|(_, cost, path)| (path, cost, Reached::Partial))
 }
 
-enum Path<N, C> {
-    Found(Vec<N>, C),
-    MinimumPath(C),
-    NoTraversal,
-}
-//computes the shortest path and dynamically change the cost of each edges to account for changing obstacle or changing environemnt
-fn compute_shortest_path<N, C, FN, IN, FH, FS>(
+#[allow(clippy::too_many_arguments)]
+fn compute_shortest_path_or_closest<N, C, FN, IN, FH, FS>(
     path: &mut Vec<N>,
     cost: C,
     bound: C,
     successors: &mut FN,
     h: &mut FH,
     success: &mut FS,
+    closest: &mut Option<(C, C, Vec<N>)>,
 ) -> Path<N, C>
 where
     N: Eq + Clone + Debug,
@@ -82,6 +363,12 @@ where
         if f > bound {
             return Path::MinimumPath(f);
         }
+        let node_h = h(start);
+        if closest.as_ref().map_or(true, |(best_h, best_cost, _)| {
+            node_h < *best_h || (node_h == *best_h && cost < *best_cost)
+        }) {
+            *closest = Some((node_h, cost, path.clone()));
+        }
         if success(start) {
             return Path::Found(path.clone(), f);
         }
@@ -100,7 +387,7 @@ where
     let mut min = None;
     for (node, extra, _) in neighbours {
         path.push(node);
-        match compute_shortest_path(path, cost + extra, bound, successors, h, success) {
+        match compute_shortest_path_or_closest(path, cost + extra, bound, successors, h, success, closest) {
             found_path @ Path::Found(_, _) => return found_path,
             Path::MinimumPath(m) => match min {
                 None => min = Some(m),
@@ -114,6 +401,80 @@ where
     min.map_or(Path::NoTraversal, Path::MinimumPath)
 }
 
+/// Compute a shortest path using the [Fringe search
+/// algorithm](https://en.wikipedia.org/wiki/Fringe_search), selectable via [`dstar_with_backend`]
+/// as [`Backend::Fringe`] or called directly. IDA* repeats work badly on wide branching factors,
+/// since each increase of `bound` re-expands the whole tree from scratch; Fringe search instead
+/// keeps a `now`/`later` pair of node lists plus a cache of the best `g`/parent seen per node, so
+/// raising the threshold only re-walks the fringe rather than re-expanding from the root.
+///
+/// `Dstar`, `Pathplanning/dstar`, and `Dstar_Lite` each carry their own Fringe search function —
+/// none of these three crates share a workspace or a common library today, so there's nowhere to
+/// hoist a shared implementation without introducing one. The three are not identical: this one
+/// and `Dstar_Lite`'s cache `g`/parent in a `HashMap` but take their arguments in different
+/// orders, while `Pathplanning/dstar`'s keeps them in an `FxIndexMap`.
+pub fn fringe<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut h: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Clone + Debug + Hash,
+    C: Zero + Ord + Copy + Debug,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut cache: HashMap<N, (C, Option<N>)> = HashMap::new();
+    cache.insert(start.clone(), (Zero::zero(), None));
+    let mut now: VecDeque<N> = VecDeque::new();
+    now.push_back(start.clone());
+    let mut later: VecDeque<N> = VecDeque::new();
+    let mut flimit = h(start);
+
+    while !now.is_empty() {
+        let mut next_flimit: Option<C> = None;
+        while let Some(node) = now.pop_front() {
+            let (g, _) = *cache.get(&node).unwrap();
+            let f = g + h(&node);
+            if f > flimit {
+                next_flimit = Some(next_flimit.map_or(f, |m| m.min(f)));
+                later.push_back(node);
+                continue;
+            }
+            if success(&node) {
+                let mut path = vec![node.clone()];
+                let mut current = node;
+                while let Some(parent) = cache.get(&current).and_then(|(_, p)| p.clone()) {
+                    path.push(parent.clone());
+                    current = parent;
+                }
+                path.reverse();
+                return Some((path, g));
+            }
+            for (succ, move_cost) in successors(&node) {
+                let new_g = g + move_cost;
+                let improved = match cache.get(&succ) {
+                    Some(&(old_g, _)) => new_g < old_g,
+                    None => true,
+                };
+                if improved {
+                    cache.insert(succ.clone(), (new_g, Some(node.clone())));
+                    now.push_front(succ);
+                }
+            }
+        }
+        let Some(next_flimit) = next_flimit else {
+            return None;
+        };
+        flimit = next_flimit;
+        std::mem::swap(&mut now, &mut later);
+    }
+    None
+}
+
 fn main(){
     static GOALLOC: (i32, i32) = (8, 6);
     let result = dstar(&(1, 1),
@@ -1,254 +1,639 @@
-/// Compute the shortest path using the D*Lite
+/// Compute the shortest path using D* Lite.
 /// This is a variate of the D* algorithm with more improved functionality.
-/// it also reuses information from  previous nodes
+/// It also reuses information from previous nodes.
+///
 /// - `start` is the starting node.
+/// - `goal` is the goal node.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 /// from the node to the successor.
-/// - `Predecessors` returns a list of predecessor for a given node, along with the cost for moving
-/// from the node to the predecessors.
-/// - `h` returns an approximation of the cost from a given node to the goal.
-/// - `g` Cost to reach the node from the start node. 
+/// - `predecessors` returns a list of predecessors for a given node, along with the cost for
+/// moving from the predecessor to the node.
+/// - `heuristic` returns an approximation of the cost from a given node to `start`.
 ///
 /// A node will never be included twice in the path as determined by the `Eq` relationship.
-///
-
+use num_traits::{Bounded, Zero};
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
-use std::num::Wrapping;
-use rand::Rng;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
 
-type NodeId = i32;
-type Cost = i32;
+struct QueueItem<N, C> {
+    node: N,
+    key: (C, C),
+}
 
-#[derive(Clone, Debug, PartialEq)]
-struct  Node {
-    x: i32,
-    y: i32,
-    g: Cost, // Cost to reach the node from the start node
-    rhs: Cost, // Cost to reach the node from the start node through the current best path
-    h: Cost, // Heuristic cost from the node to the goal node
-    successors: Vec<NodeId>,
-    predecessors: Vec<NodeId>,
+impl<N, C: PartialEq> PartialEq for QueueItem<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct QueueItem {
-    id: NodeId,
-    key: (Cost, Cost),
+impl<N, C: PartialEq> Eq for QueueItem<N, C> {}
+
+impl<N, C: Ord> PartialOrd for QueueItem<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-impl Ord for QueueItem {
+impl<N, C: Ord> Ord for QueueItem<N, C> {
     fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap; reverse so the smallest key pops first.
         other.key.cmp(&self.key)
     }
 }
 
-impl PartialOrd for QueueItem {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+/// The priority key for a vertex `s`: `(min(g, rhs) + h(start, s) + k_m, min(g, rhs))`, compared
+/// lexicographically. `k_m` is a running offset (always zero in this one-shot entry point; see
+/// the persistent [`DStarLite`] planner for the incremental version that keeps
+/// it between replans).
+fn calculate_key<C: Ord + Copy + Add<Output = C>>(g: C, rhs: C, h: C, k_m: C) -> (C, C) {
+    let min_val = g.min(rhs);
+    (min_val + h + k_m, min_val)
 }
 
-fn calculate_key(node: &Node, k_old: &(Cost, Cost)) -> (Cost, Cost) {
-    let g = node.g;
-    let rhs = node.rhs;
-    let mut rng = rand::thread_rng();
-    let h = rng.gen_range(1,10) as i32; // TODO: calculate the heuristic value
-    let min_val = g.min(rhs);
-    (min_val + h + k_old.0, min_val + k_old.1)
+fn g_of<N: Eq + Hash, C: Bounded + Copy>(g: &HashMap<N, C>, n: &N) -> C {
+    g.get(n).copied().unwrap_or_else(C::max_value)
 }
 
-fn compare(a: &(Cost, Cost), b: &(Cost, Cost)) -> bool {
-    if a.0 < b.0 {
-        true
-    } else if a.0 == b.0 && a.1 < b.1 {
-        true
+fn rhs_of<N: Eq + Hash, C: Bounded + Copy>(rhs: &HashMap<N, C>, n: &N) -> C {
+    rhs.get(n).copied().unwrap_or_else(C::max_value)
+}
+
+/// `cost + g`, saturating to `C::max_value()` if `g` is already the infinity sentinel — a plain
+/// `+` would overflow since `max_value()` has no finite successor.
+fn add_cost<C: Bounded + Ord + Copy + Add<Output = C>>(cost: C, g: C) -> C {
+    if g == C::max_value() {
+        C::max_value()
     } else {
-        false
-    }
-}
-
-fn update_node(
-    open_list: &mut BinaryHeap<QueueItem>,
-    closed_list: &mut HashMap<NodeId, (Cost, Cost)>,
-    nodes: &mut HashMap<NodeId, Node>,
-    u: &NodeId,
-    goal: &NodeId,
-    k_old: &(Cost, Cost),
-) {
-    let node = nodes.get(&u).unwrap();
-    if u != goal {
-        let mut rhs_val = 70;
-        let mut rng = rand::thread_rng();
-        for pred_id in node.predecessors.iter() {
-            let pred_node = nodes.get(&pred_id).unwrap();
-            //println!("{} days", pred_node.g);
-            let val = pred_node.g + pred_node.successors.iter().map(|&suc_id| {
-                let suc_node = nodes.get(&suc_id).unwrap();
-                if suc_node.g != i32::MIN {
-                    let cost = rng.gen_range(1,20) as i32; // TODO: calculate the cost between pred_node and suc_node
-                    pred_node.g + cost
-                } else {
-                    i32::MIN
-                }
-            }).min_by(|&a, &b| a.partial_cmp(&b).unwrap_or(Ordering::Equal)).unwrap_or(i32::MIN);
-            if val < rhs_val {
-                rhs_val = val;
+        cost + g
+    }
+}
+
+/// Recompute `rhs(s)` from its successors and update its membership/key in `U` accordingly.
+#[allow(clippy::too_many_arguments)]
+fn update_vertex<N, C, FS, FH>(
+    s: &N,
+    start: &N,
+    goal: &N,
+    g: &HashMap<N, C>,
+    rhs: &mut HashMap<N, C>,
+    queue: &mut BinaryHeap<QueueItem<N, C>>,
+    successors: &mut FS,
+    heuristic: &mut FH,
+    k_m: C,
+) where
+    N: Eq + Hash + Clone,
+    C: Zero + Bounded + Ord + Copy + Add<Output = C>,
+    FS: FnMut(&N) -> Vec<(N, C)>,
+    FH: FnMut(&N, &N) -> C,
+{
+    if s != goal {
+        let best = successors(s)
+            .into_iter()
+            .map(|(succ, c)| add_cost(c, g_of(g, &succ)))
+            .fold(C::max_value(), |a, b| a.min(b));
+        if best == C::max_value() {
+            rhs.remove(s);
+        } else {
+            rhs.insert(s.clone(), best);
+        }
+    }
+    if g_of(g, s) != rhs_of(rhs, s) {
+        let key = calculate_key(g_of(g, s), rhs_of(rhs, s), heuristic(start, s), k_m);
+        queue.push(QueueItem { node: s.clone(), key });
+    }
+}
+
+/// The canonical D* Lite `ComputeShortestPath` loop: pop the top of `U` while its key is below
+/// `key(start)` or while `start` is still locally inconsistent, settling `g` and propagating to
+/// predecessors.
+pub fn d_star_lite<N, C, FS, FP, FH>(
+    start: &N,
+    goal: &N,
+    mut successors: FS,
+    mut predecessors: FP,
+    mut heuristic: FH,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Bounded + Ord + Copy + Add<Output = C>,
+    FS: FnMut(&N) -> Vec<(N, C)>,
+    FP: FnMut(&N) -> Vec<(N, C)>,
+    FH: FnMut(&N, &N) -> C,
+{
+    let k_m = C::zero();
+    let mut g: HashMap<N, C> = HashMap::new();
+    let mut rhs: HashMap<N, C> = HashMap::new();
+    rhs.insert(goal.clone(), Zero::zero());
+
+    let mut queue = BinaryHeap::new();
+    queue.push(QueueItem {
+        node: goal.clone(),
+        key: calculate_key(g_of(&g, goal), rhs_of(&rhs, goal), heuristic(start, goal), k_m),
+    });
+
+    loop {
+        let start_h = heuristic(start, start);
+        let start_key = calculate_key(g_of(&g, start), rhs_of(&rhs, start), start_h, k_m);
+        let start_consistent = g_of(&g, start) == rhs_of(&rhs, start);
+        match queue.peek() {
+            Some(top) if top.key < start_key || !start_consistent => {}
+            _ => break,
+        }
+        let QueueItem { key: k_old, node: u } = queue.pop().unwrap();
+        let u_h = heuristic(start, &u);
+        let k_new = calculate_key(g_of(&g, &u), rhs_of(&rhs, &u), u_h, k_m);
+        if k_old < k_new {
+            queue.push(QueueItem { node: u, key: k_new });
+            continue;
+        }
+        let g_u = g_of(&g, &u);
+        let rhs_u = rhs_of(&rhs, &u);
+        if g_u == rhs_u {
+            // `u` was already consistent when this entry was queued (e.g. it got resettled by a
+            // different pop before this one came up); nothing left to propagate.
+            continue;
+        }
+        if g_u > rhs_u {
+            g.insert(u.clone(), rhs_u);
+            for (pred, _) in predecessors(&u) {
+                update_vertex(
+                    &pred, start, goal, &g, &mut rhs, &mut queue, &mut successors, &mut heuristic, k_m,
+                );
+            }
+        } else {
+            g.remove(&u);
+            update_vertex(
+                &u, start, goal, &g, &mut rhs, &mut queue, &mut successors, &mut heuristic, k_m,
+            );
+            for (pred, _) in predecessors(&u) {
+                update_vertex(
+                    &pred, start, goal, &g, &mut rhs, &mut queue, &mut successors, &mut heuristic, k_m,
+                );
             }
         }
-       //node.rhs = rhs_val;
     }
-    if let Some(k) = closed_list.get(u) {
-        if !compare(&calculate_key(node, k_old), k) {
-            return;
+
+    // Reconstruct the path by greedily descending `g` from `start`.
+    let mut path = vec![start.clone()];
+    let mut current = start.clone();
+    while current != *goal {
+        let (next, _) = successors(&current)
+            .into_iter()
+            .map(|(n, c)| {
+                let gn = g_of(&g, &n);
+                (n, add_cost(c, gn))
+            })
+            .min_by(|(_, a), (_, b)| a.cmp(b))?;
+        if g_of(&g, &next) == C::max_value() {
+            return None;
         }
+        path.push(next.clone());
+        current = next;
     }
-    //node.g = node.rhs;
-    closed_list.remove(u);
-    open_list.iter().find(|&item| item.id == *u);
-    open_list.push(QueueItem { id: *u, key: calculate_key(node, k_old) });
-}
-
-fn d_star_lite(start: NodeId, goal: NodeId, mut nodes: &mut HashMap<NodeId, Node>) -> Option<Vec<NodeId>> {
-    let  u: NodeId = goal;
-    let  k_old: (Cost, Cost) = (0, 0);
-    let mut closed_list: HashMap<NodeId, (Cost, Cost)> = HashMap::new();
-
-    // Initialize the priority queue with the start node
-    let mut open_list: BinaryHeap<QueueItem> = BinaryHeap::new();
-    let start_node = nodes.get_mut(&start).unwrap();
-    let start_key = calculate_key(&start_node, &k_old);
-    open_list.push(QueueItem { id: start, key: start_key });
-    
-    
-    //println!("{:?} empty", open_list);
-    // Update the rhs value of the start node
-    nodes.get_mut(&start).unwrap().rhs = 0;
-
-    while !open_list.is_empty() && (compare(&open_list.peek().unwrap().key, &calculate_key(&nodes.get(&u).unwrap(), &k_old)) || nodes.get(&u).unwrap().rhs != nodes.get(&u).unwrap().g) {
-        // Pop the node with the smallest key from the open list
-        let QueueItem { id: current, key: _ } = open_list.pop().unwrap();
-
-        // Check if the current node has been expanded previously
-        if let Some(k) = closed_list.get(&current) {
-            if compare(&calculate_key(&nodes.get(&current).unwrap(), &k_old), k) {
-                // If the node is consistent, update its g value
-                nodes.get_mut(&current).unwrap().g = nodes.get(&current).unwrap().rhs;
-            } else {
-                // If the node is inconsistent, update its rhs value and add it to the open list
-                nodes.get_mut(&current).unwrap().g = i32::MIN;
-                update_node(&mut open_list, &mut closed_list, &mut nodes, &current, &goal, &k_old);
+    let cost = g_of(&g, start);
+    Some((path, cost))
+}
+
+/// On-demand successor/predecessor sources for [`DStarLite`]'s lazy-expansion mode. Each is
+/// queried at most once per node; the edge costs it reports are memoized into the planner's
+/// `out_edges`/`in_edges` adjacency maps so a later touch of the same node is a plain lookup, not
+/// another call.
+struct LazyExpansion<N, C> {
+    successors: Box<dyn FnMut(&N) -> Vec<(N, C)>>,
+    predecessors: Box<dyn FnMut(&N) -> Vec<(N, C)>>,
+    successors_expanded: HashSet<N>,
+    predecessors_expanded: HashSet<N>,
+}
+
+/// A persistent D* Lite planner that keeps `g`/`rhs`/`U`/`k_m` across replans, so that edge-cost
+/// changes or a moving `start` only require touching the vertices they actually affect instead of
+/// rerunning [`d_star_lite`] from scratch.
+///
+/// Edges are supplied incrementally through [`update_edge`](DStarLite::update_edge), or, after
+/// [`enable_lazy_expansion`](DStarLite::enable_lazy_expansion), generated on demand by the
+/// closures given to it; there is no separate "static graph" input.
+pub struct DStarLite<N, C, FH> {
+    start: N,
+    goal: N,
+    k_m: C,
+    g: HashMap<N, C>,
+    rhs: HashMap<N, C>,
+    queue: BinaryHeap<QueueItem<N, C>>,
+    /// Edges keyed by source node, for [`successors`](Self::successors).
+    out_edges: HashMap<N, HashMap<N, C>>,
+    /// The same edges keyed by target node, for [`predecessors`](Self::predecessors), so neither
+    /// lookup has to scan the whole edge set.
+    in_edges: HashMap<N, HashMap<N, C>>,
+    heuristic: FH,
+    lazy: Option<LazyExpansion<N, C>>,
+}
+
+impl<N, C, FH> DStarLite<N, C, FH>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Bounded + Ord + Copy + Add<Output = C>,
+    FH: FnMut(&N, &N) -> C,
+{
+    pub fn new(start: N, goal: N, heuristic: FH) -> Self {
+        let mut rhs = HashMap::new();
+        rhs.insert(goal.clone(), C::zero());
+        let mut planner = DStarLite {
+            start,
+            goal: goal.clone(),
+            k_m: C::zero(),
+            g: HashMap::new(),
+            rhs,
+            queue: BinaryHeap::new(),
+            out_edges: HashMap::new(),
+            in_edges: HashMap::new(),
+            heuristic,
+            lazy: None,
+        };
+        planner.update_vertex(&goal);
+        planner
+    }
+
+    fn insert_edge(&mut self, from: N, to: N, cost: C) {
+        self.out_edges.entry(from.clone()).or_default().insert(to.clone(), cost);
+        self.in_edges.entry(to).or_default().insert(from, cost);
+    }
+
+    /// Like [`insert_edge`](Self::insert_edge), but never overwrites a cost already on record —
+    /// used by lazy expansion so a cost set explicitly via [`update_edge`](Self::update_edge)
+    /// before the node was ever lazily touched isn't silently replaced by the generator's answer.
+    fn insert_edge_if_absent(&mut self, from: N, to: N, cost: C) {
+        self.out_edges.entry(from.clone()).or_default().entry(to.clone()).or_insert(cost);
+        self.in_edges.entry(to).or_default().entry(from).or_insert(cost);
+    }
+
+    /// Switch to lazy expansion: `successors` is only called the first time a node's successors
+    /// are needed (to recompute its `rhs` or to follow the path through it), `predecessors` the
+    /// first time a popped node propagates to its predecessors, and their costs are cached from
+    /// then on. Any
+    /// edges already recorded via [`update_edge`](Self::update_edge) are kept and take priority.
+    pub fn enable_lazy_expansion(
+        &mut self,
+        successors: impl FnMut(&N) -> Vec<(N, C)> + 'static,
+        predecessors: impl FnMut(&N) -> Vec<(N, C)> + 'static,
+    ) where
+        N: 'static,
+        C: 'static,
+    {
+        self.lazy = Some(LazyExpansion {
+            successors: Box::new(successors),
+            predecessors: Box::new(predecessors),
+            successors_expanded: HashSet::new(),
+            predecessors_expanded: HashSet::new(),
+        });
+    }
+
+    fn successors(&mut self, n: &N) -> Vec<(N, C)> {
+        if let Some(lazy) = &mut self.lazy {
+            if !lazy.successors_expanded.contains(n) {
+                for (succ, cost) in (lazy.successors)(n) {
+                    self.insert_edge_if_absent(n.clone(), succ, cost);
+                }
+                lazy.successors_expanded.insert(n.clone());
             }
-        } else {
-            // Add the current node to the closed list
-            let k = calculate_key(&nodes.get(&current).unwrap(), &k_old);
-            closed_list.insert(current, k);
-
-            // Update the g and rhs values of the current node
-            if current != goal {
-                nodes.get_mut(&current).unwrap().g = i32::MIN;
-                update_node(&mut open_list, &mut closed_list, &mut nodes, &current, &goal, &k_old);
+        }
+        self.out_edges
+            .get(n)
+            .map(|m| m.iter().map(|(b, &c)| (b.clone(), c)).collect())
+            .unwrap_or_default()
+    }
+
+    fn predecessors(&mut self, n: &N) -> Vec<(N, C)> {
+        if let Some(lazy) = &mut self.lazy {
+            if !lazy.predecessors_expanded.contains(n) {
+                for (pred, cost) in (lazy.predecessors)(n) {
+                    self.insert_edge_if_absent(pred, n.clone(), cost);
+                }
+                lazy.predecessors_expanded.insert(n.clone());
             }
         }
+        self.in_edges
+            .get(n)
+            .map(|m| m.iter().map(|(a, &c)| (a.clone(), c)).collect())
+            .unwrap_or_default()
+    }
 
-        // Update the key values of the nodes in the open list if necessary
-        if let Some(k) = closed_list.get(&current) {
-            if compare(k, &calculate_key(&nodes.get(&current).unwrap(), &k_old)) {
-                update_node(&mut open_list, &mut closed_list, &mut nodes, &current, &goal, &k_old);
-            } else if nodes.get(&current).unwrap().g > nodes.get(&current).unwrap().rhs {
-                let new_key = calculate_key(&nodes.get(&current).unwrap(), &k_old);
-                open_list.push(QueueItem { id: current, key: new_key });
+    fn update_vertex(&mut self, s: &N) {
+        if *s != self.goal {
+            let best = self
+                .successors(s)
+                .into_iter()
+                .map(|(succ, c)| add_cost(c, g_of(&self.g, &succ)))
+                .fold(C::max_value(), |a, b| a.min(b));
+            if best == C::max_value() {
+                self.rhs.remove(s);
             } else {
-                let new_key = calculate_key(&nodes.get(&current).unwrap(), &k_old);
-                open_list.push(QueueItem { id: current, key: new_key });
-                update_node(&mut open_list, &mut closed_list, &mut nodes, &current, &goal, &k_old);
+                self.rhs.insert(s.clone(), best);
             }
-        } else {
-            let new_key = calculate_key(&nodes.get(&current).unwrap(), &k_old);
-            open_list.push(QueueItem { id: current, key: new_key });
-            update_node(&mut open_list, &mut closed_list, &mut nodes, &current, &goal, &k_old);
+        }
+        if g_of(&self.g, s) != rhs_of(&self.rhs, s) {
+            let h = (self.heuristic)(&self.start, s);
+            let key = calculate_key(g_of(&self.g, s), rhs_of(&self.rhs, s), h, self.k_m);
+            self.queue.push(QueueItem { node: s.clone(), key });
         }
     }
 
-    // Check if a path was found
-    if nodes.get(&start).unwrap().rhs == i32::MIN {
-        return None;
+    fn compute_shortest_path(&mut self) {
+        loop {
+            let start_h = (self.heuristic)(&self.start, &self.start);
+            let start_key = calculate_key(
+                g_of(&self.g, &self.start),
+                rhs_of(&self.rhs, &self.start),
+                start_h,
+                self.k_m,
+            );
+            let start_consistent = g_of(&self.g, &self.start) == rhs_of(&self.rhs, &self.start);
+            match self.queue.peek() {
+                Some(top) if top.key < start_key || !start_consistent => {}
+                _ => break,
+            }
+            let QueueItem { key: k_old, node: u } = self.queue.pop().unwrap();
+            let u_h = (self.heuristic)(&self.start, &u);
+            let k_new = calculate_key(g_of(&self.g, &u), rhs_of(&self.rhs, &u), u_h, self.k_m);
+            if k_old < k_new {
+                self.queue.push(QueueItem { node: u, key: k_new });
+                continue;
+            }
+            let g_u = g_of(&self.g, &u);
+            let rhs_u = rhs_of(&self.rhs, &u);
+            if g_u == rhs_u {
+                // Already consistent: this entry was superseded by an earlier pop and is stale.
+                continue;
+            }
+            if g_u > rhs_u {
+                self.g.insert(u.clone(), rhs_u);
+                for (pred, _) in self.predecessors(&u) {
+                    self.update_vertex(&pred);
+                }
+            } else {
+                self.g.remove(&u);
+                self.update_vertex(&u);
+                for (pred, _) in self.predecessors(&u) {
+                    self.update_vertex(&pred);
+                }
+            }
+        }
     }
 
-    // Build the path
-    let mut path: Vec<NodeId> = vec![start];
-    let mut current = start;
+    /// Record a new or changed edge cost and reincorporate it without discarding prior `g`/`rhs`
+    /// state, by calling `UpdateVertex` on just the two affected endpoints.
+    pub fn update_edge(&mut self, from: N, to: N, new_cost: C) {
+        self.insert_edge(from.clone(), to.clone(), new_cost);
+        self.update_vertex(&from);
+        self.update_vertex(&to);
+    }
 
-    while current != goal {
-        let successors = nodes.get(&current).unwrap().successors.iter().cloned().collect::<Vec<NodeId>>();
-        let mut min_g = i32::MIN;
-        let mut next: Option<NodeId> = None;
+    /// Move `start` to `new_start`, bumping `k_m` by `h(start, new_start)` so that keys already in
+    /// the queue remain valid without having to re-sort it.
+    pub fn move_start(&mut self, new_start: N) {
+        let shift = (self.heuristic)(&self.start, &new_start);
+        self.k_m = self.k_m + shift;
+        self.start = new_start;
+    }
 
-        for s in successors {
-            
-            //println!("{} empty", nodes.get(&current).unwrap().g);
-            let cost = nodes.get(&s).unwrap().g + 12;
-            if cost < min_g {
-                min_g = cost;
-                next = Some(s);
+    /// Replan (if needed) and return the shortest path from the current `start` to `goal`.
+    pub fn shortest_path(&mut self) -> Option<Vec<N>> {
+        self.compute_shortest_path();
+        let mut path = vec![self.start.clone()];
+        let mut current = self.start.clone();
+        while current != self.goal {
+            let (next, _) = self
+                .successors(&current)
+                .into_iter()
+                .map(|(n, c)| {
+                    let gn = g_of(&self.g, &n);
+                    (n, add_cost(c, gn))
+                })
+                .min_by(|(_, a), (_, b)| a.cmp(b))?;
+            if g_of(&self.g, &next) == C::max_value() {
+                return None;
             }
+            path.push(next.clone());
+            current = next;
         }
+        Some(path)
+    }
+}
 
-        match next {
-            Some(n) => {
-                path.push(n);
-                current = n;
-            },
-            None => {
-                // No path found
-                return None;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manhattan(a: &i32, b: &i32) -> i32 {
+        (a - b).abs()
+    }
+
+    #[test]
+    fn replans_after_edge_cost_increase() {
+        // 0 -> 1 -> 2 -> 3 (cost 3) beats the 0 -> 4 -> 3 detour (cost 10) until the middle edge
+        // gets expensive, at which point the next `shortest_path` call must route around it using
+        // only the vertices that edge change actually affects.
+        let mut planner = DStarLite::new(0, 3, manhattan);
+        planner.update_edge(0, 1, 1);
+        planner.update_edge(1, 2, 1);
+        planner.update_edge(2, 3, 1);
+        planner.update_edge(0, 4, 5);
+        planner.update_edge(4, 3, 5);
+        assert_eq!(planner.shortest_path(), Some(vec![0, 1, 2, 3]));
+
+        planner.update_edge(1, 2, 20);
+        assert_eq!(planner.shortest_path(), Some(vec![0, 4, 3]));
+    }
+}
+
+/// Fringe search: an iterative-deepening A* variant that keeps the current and next thresholds'
+/// frontiers as explicit `now`/`later` lists instead of re-expanding from scratch at each bound,
+/// giving IDA*-like memory behavior without its repeated-work cost.
+pub fn fringe<N, C, FS, FH>(
+    start: &N,
+    mut success: FS,
+    mut successors: impl FnMut(&N) -> Vec<(N, C)>,
+    mut heuristic: FH,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+    FS: FnMut(&N) -> bool,
+    FH: FnMut(&N) -> C,
+{
+    let mut cache: HashMap<N, (C, Option<N>)> = HashMap::new();
+    cache.insert(start.clone(), (Zero::zero(), None));
+    let mut now: VecDeque<N> = VecDeque::new();
+    now.push_back(start.clone());
+    let mut later: VecDeque<N> = VecDeque::new();
+    let mut flimit = heuristic(start);
+
+    while !now.is_empty() {
+        let mut next_flimit: Option<C> = None;
+        while let Some(node) = now.pop_front() {
+            let (g, _) = *cache.get(&node).unwrap();
+            let f = g + heuristic(&node);
+            if f > flimit {
+                next_flimit = Some(next_flimit.map_or(f, |m| m.min(f)));
+                later.push_back(node);
+                continue;
+            }
+            if success(&node) {
+                let mut path = vec![node.clone()];
+                let mut current = node;
+                while let Some(parent) = cache.get(&current).and_then(|(_, p)| p.clone()) {
+                    path.push(parent.clone());
+                    current = parent;
+                }
+                path.reverse();
+                return Some((path, g));
+            }
+            for (succ, move_cost) in successors(&node) {
+                let new_g = g + move_cost;
+                let improved = match cache.get(&succ) {
+                    Some(&(old_g, _)) => new_g < old_g,
+                    None => true,
+                };
+                if improved {
+                    cache.insert(succ.clone(), (new_g, Some(node.clone())));
+                    now.push_front(succ);
+                }
             }
         }
+        let Some(next_flimit) = next_flimit else {
+            return None;
+        };
+        flimit = next_flimit;
+        std::mem::swap(&mut now, &mut later);
     }
+    None
+}
 
-    Some(path)
+/// A candidate retained in a [`beam_search`] layer, scored by `f = g + h`.
+struct BeamCandidate<N, C> {
+    total: C,
+    g: C,
+    node: N,
 }
 
+impl<N, C: PartialEq> PartialEq for BeamCandidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.total == other.total
+    }
+}
 
-fn main() {
-    // Define the graph
-    let mut nodes: HashMap<NodeId, Node> = HashMap::new();
-    // nodes.insert(0, Node::new(0, vec![(1, 1.0), (2, 4.0)]));
-    // nodes.insert(1, Node::new(1, vec![(3, 3.0)]));
-    // nodes.insert(2, Node::new(2, vec![(1, 1.0), (3, 1.0)]));
-    // nodes.insert(3, Node::new(3, vec![(4, 2.0)]));
-    // nodes.insert(4, Node::new(4, vec![]));
-
-    // Run the D* Lite algorithm
-    let start = 0;
-    let goal = 4;
-    nodes.insert(0, Node { x: 0, y: 0, g: 8, rhs: 4 , h:16, successors: vec![9, 6], predecessors: vec![2]});
-    nodes.insert(1, Node { x: 1, y: 0, g: 4, rhs: 3, h:8 , successors: vec![5], predecessors: vec![4]});
-    nodes.insert(2, Node { x: 2, y: 0, g: 9, rhs: 8, h:15, successors: vec![7], predecessors: vec![5]});
-    nodes.insert(3, Node { x: 3, y: 0, g: 6, rhs: 2, h:17, successors: vec![8], predecessors: vec![1]});
-    nodes.insert(4, Node { x: 4, y: 0, g: 8, rhs: 1, h:13, successors: vec![1], predecessors: vec![2] });
-    nodes.insert(5, Node { x: 5, y: 0, g: 1, rhs: 8, h:8, successors: vec![0], predecessors: vec![2] });
-    nodes.insert(6, Node { x: 6, y: 0, g: 8, rhs: 3, h:16, successors: vec![5], predecessors: vec![3]});
-    nodes.insert(7, Node { x: 7, y: 0, g: 7, rhs: 6, h:19, successors: vec![6], predecessors: vec![6]});
-    nodes.insert(8, Node { x: 8, y: 0, g: 8, rhs: 5, h:11, successors: vec![3], predecessors: vec![5]});
-    nodes.insert(9, Node { x: 9, y: 0, g: 2, rhs: 9, h:10, successors: vec![6], predecessors: vec![2]});
-    let mut path = d_star_lite(start, goal,  &mut nodes);
-    println!("Path: {:?}", path);
-    // Print the path
-    match path {
-        Some(p) => println!("Path: {:?}", p),
-        None => println!("No path found"),
+impl<N, C: PartialEq> Eq for BeamCandidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for BeamCandidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    // Modify the graph
-    nodes.get_mut(&1).unwrap().predecessors.insert(1, 5);
+impl<N, C: Ord> Ord for BeamCandidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Unlike `QueueItem`, this is left as a plain max-heap: the worst (highest-`f`) candidate
+        // needs to sit on top so a layer over `beam_width` can evict it in O(log n).
+        self.total.cmp(&other.total)
+    }
+}
 
-    // Re-run the D* Lite algorithm
-    path = d_star_lite(start, goal, &mut nodes);
+/// Beam search: expand the graph in layers, scoring each successor by `f = g + h` and keeping
+/// only the `beam_width` best per layer. Trades optimality for a frontier that never grows past
+/// `beam_width`; pass `usize::MAX` to recover the unbounded, exact behavior. The search does not
+/// stop at the first goal it keeps: it runs on while some candidate is cheaper than the best goal
+/// kept so far, and the goal itself is not expanded.
+pub fn beam_search<N, C, FS, FH>(
+    start: &N,
+    goal: &N,
+    mut successors: FS,
+    mut heuristic: FH,
+    beam_width: usize,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+    FS: FnMut(&N) -> Vec<(N, C)>,
+    FH: FnMut(&N) -> C,
+{
+    let mut parent: HashMap<N, N> = HashMap::new();
+    let mut best_g: HashMap<N, C> = HashMap::new();
+    best_g.insert(start.clone(), Zero::zero());
+    if start == goal {
+        return Some((vec![start.clone()], Zero::zero()));
+    }
 
-    // Print the path
-    match path {
-        Some(p) => println!("Path: {:?}", p),
-        None => println!("No path found"),
+    // The cheapest goal kept in a beam so far, with its path.
+    let mut found: Option<(Vec<N>, C)> = None;
+    let mut layer = vec![start.clone()];
+    while !layer.is_empty() {
+        let mut beam: BinaryHeap<BeamCandidate<N, C>> = BinaryHeap::new();
+        for node in &layer {
+            let g = best_g[node];
+            for (succ, cost) in successors(node) {
+                let new_g = g + cost;
+                let improved = match best_g.get(&succ) {
+                    Some(&old_g) => new_g < old_g,
+                    None => true,
+                };
+                if !improved || found.as_ref().map_or(false, |(_, best)| new_g >= *best) {
+                    continue;
+                }
+                best_g.insert(succ.clone(), new_g);
+                parent.insert(succ.clone(), node.clone());
+                let total = new_g + heuristic(&succ);
+                beam.push(BeamCandidate { total, g: new_g, node: succ });
+                if beam.len() > beam_width {
+                    beam.pop();
+                }
+            }
+        }
+        layer = Vec::with_capacity(beam.len());
+        for candidate in beam {
+            if candidate.node == *goal {
+                let mut path = vec![candidate.node.clone()];
+                let mut current = candidate.node;
+                while let Some(p) = parent.get(&current) {
+                    path.push(p.clone());
+                    current = p.clone();
+                }
+                path.reverse();
+                found = Some((path, best_g[goal]));
+                continue;
+            }
+            layer.push(candidate.node);
+        }
     }
+    found
+}
+
+fn main() {
+    let edges: HashMap<(i32, i32), i32> = [
+        ((0, 1), 1),
+        ((0, 3), 3),
+        ((1, 2), 1),
+        ((2, 4), 3),
+        ((3, 2), 1),
+        ((3, 4), 1),
+        ((4, 5), 1),
+    ]
+    .into_iter()
+    .collect();
+
+    let successors = |n: &i32| -> Vec<(i32, i32)> {
+        edges
+            .iter()
+            .filter_map(|(&(a, b), &c)| (a == *n).then_some((b, c)))
+            .collect()
+    };
+    let predecessors = |n: &i32| -> Vec<(i32, i32)> {
+        edges
+            .iter()
+            .filter_map(|(&(a, b), &c)| (b == *n).then_some((a, c)))
+            .collect()
+    };
+
+    let path = d_star_lite(&0, &5, successors, predecessors, |_, _| 0);
+    println!("Path: {:?}", path);
 }
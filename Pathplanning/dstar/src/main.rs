@@ -2,9 +2,9 @@
 //! algorithm](https://en.wikipedia.org/wiki/D*#Pseudocode).
 
 use indexmap::map::Entry::{Occupied, Vacant};
-use num_traits::Zero;
+use num_traits::{Bounded, Zero};
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::hash::Hash;
 use std::iter::FusedIterator;
 use std::usize;
@@ -82,6 +82,163 @@ where
     None
 }
 
+/// Like [`dstar`], but when the goal cannot be reached, instead of discarding all the work done
+/// it returns the best partial path found: the path from `start` to the expanded node with the
+/// smallest `heuristic` value seen so far (ties broken by the lowest `cost`), along with its
+/// cost. This is useful for navigation/AI use cases where "get as close as possible" is more
+/// valuable than no answer at all.
+///
+/// Returns `Ok((path, cost))` if the goal was reached, or `Err((partial_path, cost))` with the
+/// path to the closest node expanded otherwise. `start` is always a candidate for "closest", so
+/// even when it has no successors at all the `Err` path is `[start]`, never empty.
+#[allow(clippy::missing_panics_doc)]
+pub fn dstar_partial<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Result<(Vec<N>, C), (Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut to_see = BinaryHeap::new();
+    to_see.push(SmallestCostHolder {
+        estimated_cost: Zero::zero(),
+        cost: Zero::zero(),
+        index: 0,
+    });
+    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::max_value(), Zero::zero()));
+    let mut closest: Option<(C, usize, C)> = None; // (h, index, cost)
+    while let Some(SmallestCostHolder { cost, index, .. }) = to_see.pop() {
+        let successors = {
+            let (node, &(_, c)) = parents.get_index(index).unwrap(); // Cannot fail
+            if success(node) {
+                let path = reverse_path(&parents, |&(p, _)| p, index);
+                return Ok((path, cost));
+            }
+            if cost > c {
+                continue;
+            }
+            let h = heuristic(node);
+            if closest.map_or(true, |(best_h, _, best_cost)| {
+                h < best_h || (h == best_h && cost < best_cost)
+            }) {
+                closest = Some((h, index, cost));
+            }
+            successors(node)
+        };
+        for (successor, move_cost) in successors {
+            let new_cost = cost + move_cost;
+            let h; // heuristic(&successor)
+            let n; // index for successor
+            match parents.entry(successor) {
+                Vacant(e) => {
+                    h = heuristic(e.key());
+                    n = e.index();
+                    e.insert((index, new_cost));
+                }
+                Occupied(mut e) => {
+                    if e.get().1 > new_cost {
+                        h = heuristic(e.key());
+                        n = e.index();
+                        e.insert((index, new_cost));
+                    } else {
+                        continue;
+                    }
+                }
+            }
+
+            to_see.push(SmallestCostHolder {
+                estimated_cost: new_cost + h,
+                cost: new_cost,
+                index: n,
+            });
+        }
+    }
+    match closest {
+        Some((_, index, cost)) => Err((reverse_path(&parents, |&(p, _)| p, index), cost)),
+        None => Err((Vec::new(), Zero::zero())),
+    }
+}
+
+/// Like [`dstar`], but `successors` is also given the parent of the node being expanded (`None`
+/// for `start`), which lets callers prune based on the direction of travel — exactly what grid
+/// pathfinding optimizations like Jump Point Search require. A user writing a grid solver can
+/// compute the incoming direction from the parent and emit only the "jump point" successors,
+/// collapsing long straight corridors into single expansions.
+#[allow(clippy::missing_panics_doc)]
+pub fn dstar_with_parent<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(Option<&N>, &N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut to_see = BinaryHeap::new();
+    to_see.push(SmallestCostHolder {
+        estimated_cost: Zero::zero(),
+        cost: Zero::zero(),
+        index: 0,
+    });
+    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::max_value(), Zero::zero()));
+    while let Some(SmallestCostHolder { cost, index, .. }) = to_see.pop() {
+        let successors = {
+            let (node, &(parent_index, c)) = parents.get_index(index).unwrap(); // Cannot fail
+            if success(node) {
+                let path = reverse_path(&parents, |&(p, _)| p, index);
+                return Some((path, cost));
+            }
+            if cost > c {
+                continue;
+            }
+            let parent = (parent_index != usize::max_value())
+                .then(|| parents.get_index(parent_index).unwrap().0);
+            successors(parent, node)
+        };
+        for (successor, move_cost) in successors {
+            let new_cost = cost + move_cost;
+            let h; // heuristic(&successor)
+            let n; // index for successor
+            match parents.entry(successor) {
+                Vacant(e) => {
+                    h = heuristic(e.key());
+                    n = e.index();
+                    e.insert((index, new_cost));
+                }
+                Occupied(mut e) => {
+                    if e.get().1 > new_cost {
+                        h = heuristic(e.key());
+                        n = e.index();
+                        e.insert((index, new_cost));
+                    } else {
+                        continue;
+                    }
+                }
+            }
+
+            to_see.push(SmallestCostHolder {
+                estimated_cost: new_cost + h,
+                cost: new_cost,
+                index: n,
+            });
+        }
+    }
+    None
+}
 
 /// The shortest paths starting from `start` up to a node for which `success` returns `true` are
 /// computed and returned in an iterator along with the cost (which, by definition, is the same for
@@ -223,6 +380,275 @@ where
         .map(|(solutions, cost)| (solutions.collect(), cost))
 }
 
+/// Compute a shortest path using the [Fringe search
+/// algorithm](https://en.wikipedia.org/wiki/Fringe_search), an alternative to [`dstar`] that
+/// trades the `BinaryHeap` for a pair of `VecDeque`s and an iterative-deepening `f`-bound. On
+/// uniform-cost grids, where many nodes share the same `f = g + h` value, this avoids the
+/// binary-heap overhead of repeatedly re-sorting equally-good candidates.
+///
+/// Arguments have the same meaning as in [`dstar`].
+#[allow(clippy::missing_panics_doc)]
+pub fn fringe<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::max_value(), Zero::zero()));
+    let mut now: VecDeque<usize> = VecDeque::new();
+    now.push_back(0);
+    let mut later: VecDeque<usize> = VecDeque::new();
+    let mut flimit = heuristic(start);
+    while !now.is_empty() {
+        let mut next_flimit = None;
+        while let Some(index) = now.pop_front() {
+            let (node, &(_, cost)) = parents.get_index(index).unwrap(); // Cannot fail
+            let f = cost + heuristic(node);
+            if f > flimit {
+                next_flimit = Some(next_flimit.map_or(f, |m: C| m.min(f)));
+                later.push_back(index);
+                continue;
+            }
+            if success(node) {
+                let path = reverse_path(&parents, |&(p, _)| p, index);
+                return Some((path, cost));
+            }
+            let successors = successors(node).into_iter().collect::<Vec<_>>();
+            for (successor, move_cost) in successors {
+                let new_cost = cost + move_cost;
+                let n;
+                match parents.entry(successor) {
+                    Vacant(e) => {
+                        n = e.index();
+                        e.insert((index, new_cost));
+                    }
+                    Occupied(mut e) => {
+                        if e.get().1 > new_cost {
+                            n = e.index();
+                            e.insert((index, new_cost));
+                        } else {
+                            continue;
+                        }
+                    }
+                }
+                now.push_front(n);
+            }
+        }
+        let Some(next_flimit) = next_flimit else {
+            return None;
+        };
+        flimit = next_flimit;
+        std::mem::swap(&mut now, &mut later);
+    }
+    None
+}
+
+/// The `successors` closure yielded an edge that, combined with the rest of the graph, forms a
+/// negative cycle reachable from `start`, so no shortest path exists.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NegativeCycle;
+
+/// Compute a shortest path using the [Bellman-Ford
+/// algorithm](https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm), which tolerates
+/// negative edge costs that would silently produce wrong answers from [`dstar`]'s `C: Zero + Ord`
+/// bound together with an inadmissible heuristic. No `heuristic` is needed since the algorithm
+/// explores the whole reachable graph rather than guiding the search toward a `success` node.
+///
+/// The pending-node queue is a deque managed with the Small-Label-First / Large-Label-Last
+/// refinements: a newly relaxed node is pushed to the front of the queue if its tentative
+/// distance is smaller than the current front node's, otherwise to the back (SLF); before a node
+/// is popped, if its distance exceeds the running average distance of all queued nodes it is
+/// rotated to the back instead, so cheaper nodes are processed first (LLL). Each node's
+/// relaxation count is tracked, and if any node is relaxed more than `|V|` times the search
+/// returns [`NegativeCycle`] instead of looping forever.
+#[allow(clippy::missing_panics_doc)]
+pub fn bellman_ford<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Result<Option<(Vec<N>, C)>, NegativeCycle>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + std::ops::Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut dist: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    dist.insert(start.clone(), (usize::max_value(), Zero::zero()));
+    let mut relax_count: HashMap<usize, usize> = HashMap::new();
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    queue.push_back(0);
+    let mut queued: HashSet<usize> = HashSet::new();
+    queued.insert(0);
+    let mut goal_index: Option<(usize, C)> = None;
+
+    while !queue.is_empty() {
+        // LLL: rotate the front node to the back while it is costlier than the average of the
+        // queue, so the cheapest candidates get relaxed first.
+        let total: C = queue
+            .iter()
+            .map(|&i| dist.get_index(i).unwrap().1 .1)
+            .fold(Zero::zero(), |a, b| a + b);
+        let mut average_guard = 0;
+        while queue.len() > 1 && average_guard < queue.len() {
+            let &front = queue.front().unwrap();
+            let front_cost = dist.get_index(front).unwrap().1 .1;
+            // Compare against a simple running average; `total`/`len` both use the same `C`
+            // arithmetic already required by the rest of the crate.
+            let len = queue.len();
+            let mut acc: C = Zero::zero();
+            for _ in 0..len {
+                acc = acc + front_cost;
+            }
+            if acc > total {
+                queue.rotate_left(1);
+                average_guard += 1;
+            } else {
+                break;
+            }
+        }
+
+        let index = queue.pop_front().unwrap();
+        queued.remove(&index);
+        let (node, &(_, cost)) = dist.get_index(index).unwrap();
+        // Unlike `dstar`, we cannot return as soon as a success node is first reached: with
+        // negative edges a later relaxation may still shorten the distance to it. The best
+        // distance found so far is only final once the queue drains.
+        if success(node) {
+            match goal_index {
+                Some((_, best_cost)) if best_cost <= cost => {}
+                _ => goal_index = Some((index, cost)),
+            }
+        }
+        let node = node.clone();
+        for (successor, move_cost) in successors(&node) {
+            let new_cost = cost + move_cost;
+            let n;
+            let improved = match dist.entry(successor) {
+                Vacant(e) => {
+                    n = e.index();
+                    e.insert((index, new_cost));
+                    true
+                }
+                Occupied(mut e) => {
+                    if e.get().1 > new_cost {
+                        n = e.index();
+                        e.insert((index, new_cost));
+                        true
+                    } else {
+                        n = e.index();
+                        false
+                    }
+                }
+            };
+            if !improved {
+                continue;
+            }
+            let count = relax_count.entry(n).or_insert(0);
+            *count += 1;
+            if *count > dist.len() {
+                return Err(NegativeCycle);
+            }
+            if queued.insert(n) {
+                match queue.front() {
+                    Some(&front) if dist.get_index(front).unwrap().1 .1 > new_cost => {
+                        queue.push_front(n);
+                    }
+                    _ => queue.push_back(n),
+                }
+            }
+        }
+    }
+    Ok(goal_index.map(|(index, cost)| (reverse_path(&dist, |&(p, _)| p, index), cost)))
+}
+
+/// Like [`dstar`], but `successors` returns an `Iterator` instead of an `IntoIterator`, and that
+/// iterator is polled one edge at a time as the node is expanded, just as [`dstar`] walks its
+/// `IntoIterator` without collecting or sorting it. `successors` is not called at all for a node
+/// that turns out not to need expanding — e.g. it was popped stale, or `success` already matched
+/// it — which matters when generating even one edge is non-trivial (a database lookup, a set
+/// intersection).
+///
+/// Once a node *is* expanded, every edge its iterator yields is still pulled and pushed to the
+/// frontier: there is no "remaining edges can't improve on the best seen so far" cutoff, since
+/// `successors` is not required to yield edges in nondecreasing cost, and a later edge might be
+/// the only way to reach `success` more cheaply.
+#[allow(clippy::missing_panics_doc)]
+pub fn dstar_lazy<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: Iterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut to_see = BinaryHeap::new();
+    to_see.push(SmallestCostHolder {
+        estimated_cost: Zero::zero(),
+        cost: Zero::zero(),
+        index: 0,
+    });
+    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::max_value(), Zero::zero()));
+    while let Some(SmallestCostHolder { cost, index, .. }) = to_see.pop() {
+        let mut successors = {
+            let (node, &(_, c)) = parents.get_index(index).unwrap(); // Cannot fail
+            if success(node) {
+                let path = reverse_path(&parents, |&(p, _)| p, index);
+                return Some((path, cost));
+            }
+            if cost > c {
+                continue;
+            }
+            successors(node)
+        };
+        while let Some((successor, move_cost)) = successors.next() {
+            let new_cost = cost + move_cost;
+            let h; // heuristic(&successor)
+            let n; // index for successor
+            match parents.entry(successor) {
+                Vacant(e) => {
+                    h = heuristic(e.key());
+                    n = e.index();
+                    e.insert((index, new_cost));
+                }
+                Occupied(mut e) => {
+                    if e.get().1 > new_cost {
+                        h = heuristic(e.key());
+                        n = e.index();
+                        e.insert((index, new_cost));
+                    } else {
+                        continue;
+                    }
+                }
+            }
+
+            to_see.push(SmallestCostHolder {
+                estimated_cost: new_cost + h,
+                cost: new_cost,
+                index: n,
+            });
+        }
+    }
+    None
+}
+
 struct SmallestCostHolder<K> {
     estimated_cost: K,
     cost: K,
@@ -313,3 +739,245 @@ impl<N: Clone + Eq + Hash> Iterator for DstarSolution<N> {
 }
 
 impl<N: Clone + Eq + Hash> FusedIterator for DstarSolution<N> {}
+
+/// A true incremental D* Lite planner.
+///
+/// Unlike [`dstar`], which is a plain A* search re-run from scratch on every call, `DStarLite`
+/// keeps enough state between calls (a `g`/`rhs` value and a queue membership per node) that it
+/// can cheaply replan after [`update_edge`](DStarLite::update_edge) changes a single edge cost,
+/// touching only the region of the search affected by the change.
+///
+/// The search runs backward from `goal` to `start`, maintaining for every node:
+/// - `g(s)`: the current best estimate of the cost from `s` to the goal.
+/// - `rhs(s)`: a one-step lookahead value, `rhs(goal) = 0` and otherwise the minimum over
+///   successors `s'` of `c(s, s') + g(s')`.
+///
+/// A node is *locally consistent* when `g(s) == rhs(s)`. The priority queue `U` holds every
+/// locally inconsistent node, keyed by `key(s) = (min(g(s), rhs(s)) + h(start, s) + k_m,
+/// min(g(s), rhs(s)))` compared lexicographically, where `k_m` is a running offset bumped every
+/// time the agent moves so that previously computed keys stay valid without re-sorting `U`.
+pub struct DStarLite<N, C> {
+    goal: N,
+    start: N,
+    last_start: N,
+    k_m: C,
+    g: FxIndexMap<N, C>,
+    rhs: FxIndexMap<N, C>,
+    queue: BinaryHeap<DStarKeyedNode<N, C>>,
+    in_queue: HashMap<N, (C, C)>,
+}
+
+#[derive(PartialEq)]
+struct DStarKeyedNode<N, C> {
+    key: (C, C),
+    node: N,
+}
+
+impl<N: PartialEq, C: PartialEq> Eq for DStarKeyedNode<N, C> {}
+
+impl<N: PartialEq, C: PartialOrd> PartialOrd for DStarKeyedNode<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: PartialEq, C: PartialOrd> Ord for DStarKeyedNode<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap; reverse the comparison so the smallest key comes out first.
+        other
+            .key
+            .0
+            .partial_cmp(&self.key.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.key.1.partial_cmp(&self.key.1).unwrap_or(Ordering::Equal))
+    }
+}
+
+impl<N, C> DStarLite<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Bounded + Ord + Copy,
+{
+    /// Create a planner searching backward from `goal` toward `start`.
+    pub fn new(start: &N, goal: &N) -> Self {
+        let mut rhs = FxIndexMap::default();
+        rhs.insert(goal.clone(), Zero::zero());
+        let mut planner = DStarLite {
+            goal: goal.clone(),
+            start: start.clone(),
+            last_start: start.clone(),
+            k_m: Zero::zero(),
+            g: FxIndexMap::default(),
+            rhs,
+            queue: BinaryHeap::new(),
+            in_queue: HashMap::new(),
+        };
+        let key = planner.calculate_key(goal, &mut |_| Zero::zero());
+        planner.push(goal.clone(), key);
+        planner
+    }
+
+    fn g_of(&self, n: &N) -> C {
+        // A node that has never been touched is `+inf`, represented by the largest value `C`
+        // can hold since there is no generic infinity.
+        self.g.get(n).copied().unwrap_or_else(C::max_value)
+    }
+
+    fn rhs_of(&self, n: &N) -> C {
+        self.rhs.get(n).copied().unwrap_or_else(C::max_value)
+    }
+
+    /// `cost + g`, saturating to `C::max_value()` if `g` is already the infinity sentinel — a
+    /// plain `+` would overflow since `max_value()` has no finite successor.
+    fn add_cost(cost: C, g: C) -> C {
+        if g == C::max_value() {
+            C::max_value()
+        } else {
+            cost + g
+        }
+    }
+
+    fn calculate_key<FH>(&self, s: &N, heuristic: &mut FH) -> (C, C)
+    where
+        FH: FnMut(&N) -> C,
+    {
+        let min = self.g_of(s).min(self.rhs_of(s));
+        (min + heuristic(s) + self.k_m, min)
+    }
+
+    fn push(&mut self, node: N, key: (C, C)) {
+        self.in_queue.insert(node.clone(), key);
+        self.queue.push(DStarKeyedNode { key, node });
+    }
+
+    /// Recompute `rhs` for `s` and update its membership/key in `U` accordingly.
+    fn update_vertex<FN, IN, FH>(&mut self, s: &N, successors: &mut FN, heuristic: &mut FH)
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+    {
+        if *s != self.goal {
+            let mut best: Option<C> = None;
+            for (succ, cost) in successors(s) {
+                let candidate = Self::add_cost(cost, self.g_of(&succ));
+                best = Some(best.map_or(candidate, |b| b.min(candidate)));
+            }
+            match best {
+                Some(v) => {
+                    self.rhs.insert(s.clone(), v);
+                }
+                None => {
+                    self.rhs.shift_remove(s);
+                }
+            }
+        }
+        self.in_queue.remove(s);
+        if self.g_of(s) != self.rhs_of(s) {
+            let key = self.calculate_key(s, heuristic);
+            self.push(s.clone(), key);
+        }
+    }
+
+    /// Recompute the shortest path estimate, processing every locally inconsistent node whose
+    /// key is smaller than `key(start)`, or while `start` itself is still inconsistent.
+    pub fn compute_shortest_path<FN, IN, FP, IP, FH>(
+        &mut self,
+        successors: &mut FN,
+        predecessors: &mut FP,
+        heuristic: &mut FH,
+    ) where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FP: FnMut(&N) -> IP,
+        IP: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+    {
+        loop {
+            let start_key = self.calculate_key(&self.start.clone(), heuristic);
+            let Some(top) = self.queue.peek() else {
+                break;
+            };
+            let start_consistent = self.g_of(&self.start) == self.rhs_of(&self.start);
+            if top.key >= start_key && start_consistent {
+                break;
+            }
+            let DStarKeyedNode { key: k_old, node: u } = self.queue.pop().unwrap();
+            // The heap entry carries its node directly, so recovering `u` never has to guess
+            // among nodes that happen to share a key. But the entry may still be stale: `u` may
+            // have been requeued with a newer key since this entry was pushed, or may have
+            // become consistent and left `U` entirely (`update_vertex` removes it from
+            // `in_queue` in that case) — either way, skip it.
+            match self.in_queue.get(&u) {
+                Some(&current_key) if current_key == k_old => {}
+                _ => continue,
+            }
+            let k_new = self.calculate_key(&u, heuristic);
+            if k_old < k_new {
+                self.push(u.clone(), k_new);
+                continue;
+            }
+            let g_u = self.g_of(&u);
+            let rhs_u = self.rhs_of(&u);
+            if g_u > rhs_u {
+                self.g.insert(u.clone(), rhs_u);
+                self.in_queue.remove(&u);
+                for (pred, _) in predecessors(&u) {
+                    self.update_vertex(&pred, successors, heuristic);
+                }
+            } else {
+                // Underconsistent: dropping `g(u)` makes `g_of` report `C::max_value()`, the
+                // stand-in for `+inf`.
+                self.g.shift_remove(&u);
+                self.update_vertex(&u, successors, heuristic);
+                for (pred, _) in predecessors(&u) {
+                    self.update_vertex(&pred, successors, heuristic);
+                }
+            }
+        }
+    }
+
+    /// Notify the planner that the cost of the edge `u -> v` changed, so the next
+    /// [`compute_shortest_path`](Self::compute_shortest_path) call only touches the affected
+    /// region instead of recomputing the whole graph. Only `rhs(u)` is recomputed, from the costs
+    /// `successors` reports now; `v` and `new_cost` are not read, so the new cost takes effect
+    /// only through `successors`.
+    pub fn update_edge<FN, IN, FH>(&mut self, u: &N, _v: &N, _new_cost: C, successors: &mut FN, heuristic: &mut FH)
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+    {
+        self.update_vertex(u, successors, heuristic);
+    }
+
+    /// Move the agent to `new_start`, bumping `k_m` so previously computed keys remain valid.
+    pub fn step<FH>(&mut self, new_start: N, mut heuristic: FH)
+    where
+        FH: FnMut(&N, &N) -> C,
+    {
+        self.k_m = self.k_m + heuristic(&self.last_start, &new_start);
+        self.last_start = new_start.clone();
+        self.start = new_start;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bellman_ford_detects_negative_cycle() {
+        // 0 -> 1 (cost 1) -> 0 (cost -2): going around the cycle keeps lowering the distance
+        // back to 0, so there is no shortest path and the search must report the cycle instead
+        // of relaxing it forever.
+        let successors = |n: &i32| -> Vec<(i32, i32)> {
+            match n {
+                0 => vec![(1, 1)],
+                1 => vec![(0, -2)],
+                _ => vec![],
+            }
+        };
+        assert_eq!(bellman_ford(&0, successors, |_| false), Err(NegativeCycle));
+    }
+}
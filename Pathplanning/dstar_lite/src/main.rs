@@ -1,343 +1,406 @@
-use ndarray::{Array2, ArrayView2};
+use ndarray::Array2;
+use num_traits::{Bounded, Zero};
 use std::cmp::Ordering;
-
-#[derive(Copy, Clone, Debug, PartialEq)]
-enum State {
-    New,
-    Open,
-    Closed,
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Decouples the D* Lite search core from any particular graph representation, following the
+/// petgraph style of keeping the algorithm generic over how nodes and edges are stored. A user
+/// can run D* Lite over road networks, weighted lattices, or state lattices by implementing this
+/// trait instead of materializing a dense grid like [`GridGraph`].
+pub trait DStarGraph {
+    type NodeId: Eq + Hash + Clone;
+    type Cost: Zero + Bounded + Ord + Copy + Add<Output = Self::Cost>;
+
+    /// Nodes reachable from `node`, along with the cost of moving there.
+    fn successors(&self, node: &Self::NodeId) -> Vec<(Self::NodeId, Self::Cost)>;
+
+    /// Nodes that can reach `node` directly, along with the cost of that edge.
+    fn predecessors(&self, node: &Self::NodeId) -> Vec<(Self::NodeId, Self::Cost)>;
+
+    /// An admissible estimate of the cost from `a` to `b`.
+    fn heuristic(&self, a: &Self::NodeId, b: &Self::NodeId) -> Self::Cost;
 }
 
-#[derive(Copy, Clone, Debug)]
-struct Node {
-    x: usize,
-    y: usize,
-    rhs: f32,
-    g: f32,
-    state: State,
+/// An entry in the D* Lite priority queue `U`, ordered by `key` (smallest first).
+struct QueueItem<Id, C> {
+    key: (C, C),
+    node: Id,
 }
 
-impl Node {
-    fn new(x: usize, y: usize, g: f32) -> Self {
-        Self {
-            x,
-            y,
-            g,
-            rhs: f32::MAX,
-            state: State::New,
-        }
+impl<Id, C: PartialEq> PartialEq for QueueItem<Id, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
     }
+}
 
-    fn key(&self) -> (f32, f32) {
-        (self.rhs + self.g, self.rhs)
-    }
+impl<Id, C: PartialEq> Eq for QueueItem<Id, C> {}
 
-    fn is_new(&self) -> bool {
-        self.state == State::New
+impl<Id, C: Ord> PartialOrd for QueueItem<Id, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    fn is_open(&self) -> bool {
-        self.state == State::Open
+impl<Id, C: Ord> Ord for QueueItem<Id, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap; reverse so the smallest key pops first.
+        other.key.cmp(&self.key)
     }
+}
 
-    fn is_closed(&self) -> bool {
-        self.state == State::Closed
-    }
+/// The pending-vertex queue `U`, with two interchangeable strategies: a plain `BinaryHeap`, or a
+/// double-ended queue ordered with the Small-Label-First / Large-Label-Last refinements. Frequent
+/// localized cost changes (a moving agent flipping a handful of obstacles) push and pop many
+/// small-delta updates; SLF/LLL keeps those cheap without paying the heap's full re-sort.
+enum ReplanQueue<Id, C> {
+    Heap(BinaryHeap<QueueItem<Id, C>>),
+    /// SLF/LLL ordered: a relaxed vertex is pushed to the front if its key is smaller than the
+    /// current front's, otherwise to the back (SLF); before popping, a front whose key exceeds
+    /// the running average of the queued keys is rotated to the back instead (LLL).
+    SlfLll(VecDeque<QueueItem<Id, C>>),
+}
 
-    fn set_new(&mut self) {
-        self.state = State::New;
+impl<Id: Clone, C: Zero + Bounded + Ord + Copy> ReplanQueue<Id, C> {
+    fn push(&mut self, item: QueueItem<Id, C>) {
+        match self {
+            ReplanQueue::Heap(heap) => heap.push(item),
+            ReplanQueue::SlfLll(deque) => match deque.front() {
+                Some(front) if item.key < front.key => deque.push_front(item),
+                _ => deque.push_back(item),
+            },
+        }
     }
 
-    fn set_open(&mut self) {
-        self.state = State::Open;
+    fn peek(&mut self) -> Option<&QueueItem<Id, C>> {
+        if let ReplanQueue::SlfLll(deque) = self {
+            rotate_while_above_average(deque);
+        }
+        match self {
+            ReplanQueue::Heap(heap) => heap.peek(),
+            ReplanQueue::SlfLll(deque) => deque.front(),
+        }
     }
 
-    fn set_closed(&mut self) {
-        self.state = State::Closed;
+    fn pop(&mut self) -> Option<QueueItem<Id, C>> {
+        if let ReplanQueue::SlfLll(deque) = self {
+            rotate_while_above_average(deque);
+        }
+        match self {
+            ReplanQueue::Heap(heap) => heap.pop(),
+            ReplanQueue::SlfLll(deque) => deque.pop_front(),
+        }
     }
 }
 
-// This implementation takes in a 2D boolean map representing the environment, where true values represent obstacles and false values represent free spaces. It also takes in the start and goal positions as tuples of row and column indices.
-
-// The function creates a new 2D array of Node structs, initialized with the appropriate g and rhs values based on the map. It sets the g value of the goal node to infinity and the rhs value to 0, and sets the g value of the start node to 0 and the rhs value to its key.
-
-// The function returns the 2D array of nodes and the goal node as a tuple.
-
-fn initialize(map: &Array2<bool>, start: (usize, usize), goal: (usize, usize)) -> (Array2<Node>, Node) {
-    let (nrows, ncols) = map.dim();
+/// `a + b`, saturating to `C::max_value()` instead of overflowing — keys routinely carry the
+/// infinity sentinel for inconsistent vertices, and a plain `+` has no finite successor for it.
+fn saturating_add<C: Bounded + Ord + Copy + Add<Output = C>>(a: C, b: C) -> C {
+    if a == C::max_value() || b == C::max_value() {
+        C::max_value()
+    } else {
+        a + b
+    }
+}
 
-    let mut nodes = Array2::from_shape_fn((nrows, ncols), |(i, j)| {
-        if map[(i, j)] {
-            Node::new(i, j, f32::INFINITY)
+/// LLL: rotate the front of `deque` to the back while its priority exceeds the running average
+/// of all queued priorities, bounded to one full lap so ties can't spin forever.
+fn rotate_while_above_average<Id, C: Zero + Bounded + Ord + Copy>(
+    deque: &mut VecDeque<QueueItem<Id, C>>,
+) {
+    if deque.len() <= 1 {
+        return;
+    }
+    let total: C = deque
+        .iter()
+        .map(|item| item.key.0)
+        .fold(Zero::zero(), saturating_add);
+    for _ in 0..deque.len() {
+        let front = deque.front().unwrap().key.0;
+        if front == C::max_value() {
+            // An infinite-key vertex is trivially worse than the average; defer it without
+            // computing `front * len`, which would overflow immediately.
+            deque.rotate_left(1);
+            continue;
+        }
+        let mut scaled: C = Zero::zero();
+        for _ in 0..deque.len() {
+            scaled = saturating_add(scaled, front);
+        }
+        if scaled > total {
+            deque.rotate_left(1);
         } else {
-            Node::new(i, j, f32::MAX)
+            break;
         }
-    });
-
-    let goal_node = nodes.get_mut(goal.0, goal.1).unwrap();
-    goal_node.g = f32::INFINITY;
-    goal_node.rhs = 0.0;
-
-    let start_node = nodes.get_mut(start.0, start.1).unwrap();
-    start_node.g = 0.0;
-    start_node.rhs = start_node.key().1;
-
-    (nodes, *goal_node)
+    }
 }
-// This implementation takes in a 2D array of Node structs nodes, a Node node representing the current node, and a successor position as a tuple (usize, usize).
-
-// The function first checks if the successor position is the same as the current node's position, in which case it returns a cost of 0.
-
-// Next, it checks if the successor position is within the bounds of the nodes array. If it's not, the function returns None.
-
-// If the successor position is valid, the function retrieves the Node struct corresponding to that position. If that node is closed, the function also returns None.
 
-// Finally, the function computes the cost to get from the current node to the successor node based on their positions and g-values, and returns it as an Option<f32>. If any of the checks fail, the function returns None.
-fn cost_compute(
-    nodes: &ArrayView2<Node>,
-    node: &Node,
-    successor: &(usize, usize),
-) -> Option<f32> {
-    let (x, y) = successor;
-    let (nx, ny) = (node.x as isize, node.y as isize);
-    let (sx, sy) = (nodes.raw_dim()[0] as isize, nodes.raw_dim()[1] as isize);
+/// An incremental D* Lite planner, generic over any [`DStarGraph`].
+///
+/// Replanning after [`update_edge_cost`](Self::update_edge_cost) touches a vertex only
+/// propagates through the region of the graph affected by the change: `compute_shortest_path`
+/// resumes from the existing `g`/`rhs` values and priority queue instead of starting over.
+pub struct DStarLite<G: DStarGraph> {
+    graph: G,
+    start: G::NodeId,
+    last_start: G::NodeId,
+    goal: G::NodeId,
+    k_m: G::Cost,
+    g: HashMap<G::NodeId, G::Cost>,
+    rhs: HashMap<G::NodeId, G::Cost>,
+    queue: ReplanQueue<G::NodeId, G::Cost>,
+}
 
-    if *successor == (node.x, node.y) {
-        return Some(0.0);
+impl<G: DStarGraph> DStarLite<G> {
+    /// Plan with the default `BinaryHeap`-backed queue.
+    pub fn new(graph: G, start: G::NodeId, goal: G::NodeId) -> Self {
+        Self::with_queue(graph, start, goal, ReplanQueue::Heap(BinaryHeap::new()))
     }
 
-    if !((0..sx).contains(&nx + x as isize) && (0..sy).contains(&ny + y as isize)) {
-        return None;
+    /// Plan with the SLF/LLL double-ended queue instead of the `BinaryHeap`, which tends to
+    /// replan faster when the agent triggers many small, localized edge-cost changes.
+    pub fn new_with_slf_lll(graph: G, start: G::NodeId, goal: G::NodeId) -> Self {
+        Self::with_queue(graph, start, goal, ReplanQueue::SlfLll(VecDeque::new()))
     }
 
-    let successor_node = nodes.get(*x, *y)?;
-
-    if successor_node.is_closed() {
-        return None;
+    fn with_queue(
+        graph: G,
+        start: G::NodeId,
+        goal: G::NodeId,
+        queue: ReplanQueue<G::NodeId, G::Cost>,
+    ) -> Self {
+        let mut rhs = HashMap::new();
+        rhs.insert(goal.clone(), Zero::zero());
+        let mut planner = DStarLite {
+            graph,
+            start: start.clone(),
+            last_start: start,
+            goal: goal.clone(),
+            k_m: Zero::zero(),
+            g: HashMap::new(),
+            rhs,
+            queue,
+        };
+        let key = planner.calculate_key(&goal);
+        planner.queue.push(QueueItem { key, node: goal });
+        planner
     }
 
-    let dx = (x as isize - nx).abs();
-    let dy = (y as isize - ny).abs();
-    let cost = if dx + dy == 1 {
-        1.0
-    } else {
-        1.4142135623730951
-    };
-
-    Some(cost + successor_node.g - node.g)
-}
-
-// update_vertex function works:
-
-// First, the function retrieves a mutable reference to the node to update from the nodes array.
-
-// Then, if the current node is not the goal node, the function calculates a new rhs (right-hand-side) value for the node by considering the costs of reaching each of its successors. If a successor node is "new" (i.e., has not been visited before), its rhs value is set to the cost of reaching it plus its current g value. If a successor node is "open" (i.e., has been visited before and is still open for expansion), its rhs value is set to the minimum of its current rhs value and the cost of reaching it plus the g value of the current node plus the cost of the edge between the two nodes. If a successor node is "closed" (i.e., has been visited before but is not open for expansion anymore), its rhs value is not updated.
+    fn g(&self, n: &G::NodeId) -> G::Cost {
+        self.g.get(n).copied().unwrap_or_else(G::Cost::max_value)
+    }
 
-// Once the rhs values of all the successors have been considered, the function updates the rhs value of the current node to be the minimum of the rhs values of all its successors.
+    fn rhs(&self, n: &G::NodeId) -> G::Cost {
+        self.rhs.get(n).copied().unwrap_or_else(G::Cost::max_value)
+    }
 
-// If the current node is open, the function updates its g value to be the minimum of its rhs value and its current g value. If the g value has changed, the node is marked as closed and its neighbors are recursively updated using the update_vertex function. If the g value has not changed, the node remains open.
+    /// `cost + g`, saturating to `G::Cost::max_value()` if `g` is already the infinity sentinel —
+    /// a plain `+` would overflow since `max_value()` has no finite successor.
+    fn add_cost(cost: G::Cost, g: G::Cost) -> G::Cost {
+        if g == G::Cost::max_value() {
+            G::Cost::max_value()
+        } else {
+            cost + g
+        }
+    }
 
-// If the current node is not open but is new, it is marked as open and its neighbors are recursively updated using the update_vertex function.
+    fn calculate_key(&self, n: &G::NodeId) -> (G::Cost, G::Cost) {
+        let min_val = self.g(n).min(self.rhs(n));
+        (min_val + self.graph.heuristic(&self.start, n) + self.k_m, min_val)
+    }
 
-// If the current node is not open and is not new, its g value and rhs value may need to be updated based on the g values and rhs values of its predecessors. If the rhs value of the current node is equal to its previous g value, the function checks if any predecessor nodes can reach the current node with a lower cost than before. If so, the function updates the rhs value of the current node accordingly. Then, the function checks if the g value of the current node needs to be updated based on its new rhs value. If the g value has changed, the node is marked as open and its neighbors are recursively updated using the update_vertex function. If the g value has not changed, the node remains closed.
-fn update_vertex(
-    nodes: &mut Array2<Node>,
-    node: &(usize, usize),
-    start: &(usize, usize),
-    goal: &Node,
-) {
-    let mut u = nodes.get_mut(node.0, node.1).unwrap();
-
-    if u != goal {
-        let mut rhs = f32::INFINITY;
-
-        for successor in successors() {
-            if let Some(cost) = cost_compute(&nodes.view(), &u, &successor) {
-                let (x, y) = successor;
-                let successor_node = nodes.get(x, y).unwrap();
-
-                if successor_node.is_new() {
-                    rhs = rhs.min(cost + successor_node.g);
-                } else if successor_node.is_open() {
-                    rhs = rhs.min(cost + successor_node.g);
-                    let pred_cost = cost_compute(&nodes.view(), &successor_node, node).unwrap();
-                    if pred_cost + u.g < successor_node.g {
-                        successor_node.rhs = pred_cost + u.g;
-                        successor_node.g = pred_cost + u.g;
-                        nodes.get_mut(successor.0, successor.1).unwrap().set_open();
-                    }
+    /// Recompute `rhs(n)` and update its membership/key in `U`; touches only the heap, never
+    /// recurses into neighbors (callers propagate to predecessors explicitly).
+    fn update_vertex(&mut self, n: &G::NodeId) {
+        if *n != self.goal {
+            let mut best = G::Cost::max_value();
+            for (succ, cost) in self.graph.successors(n) {
+                let candidate = Self::add_cost(cost, self.g(&succ));
+                if candidate < best {
+                    best = candidate;
                 }
             }
+            if best == G::Cost::max_value() {
+                self.rhs.remove(n);
+            } else {
+                self.rhs.insert(n.clone(), best);
+            }
+        }
+        if self.g(n) != self.rhs(n) {
+            let key = self.calculate_key(n);
+            self.queue.push(QueueItem {
+                key,
+                node: n.clone(),
+            });
         }
-
-        u.rhs = rhs;
     }
 
-    if u.is_open() {
-        let k_old = u.key();
-        let k_new = (u.rhs + u.g, u.rhs);
-        if k_old < k_new {
-            nodes.get_mut(u.x, u.y).unwrap().g = u.rhs + u.g;
-            nodes.get_mut(u.x, u.y).unwrap().set_closed();
-
-            for neighbor in neighbors(node) {
-                if let Some(cost) = cost_compute(&nodes.view(), &u, &neighbor) {
-                    update_vertex(nodes, &neighbor, start, goal);
-                }
+    /// The canonical D* Lite fixpoint loop: pop the top of `U` while its key is below `key(start)`
+    /// or while `start` is still locally inconsistent, (re-)settling `g` and propagating to
+    /// predecessors.
+    pub fn compute_shortest_path(&mut self) {
+        loop {
+            let start_key = self.calculate_key(&self.start);
+            let start_consistent = self.g(&self.start) == self.rhs(&self.start);
+            match self.queue.peek() {
+                Some(top) if top.key < start_key || !start_consistent => {}
+                _ => break,
             }
-        } else {
-            let mut g_old = u.g;
-            let mut rhs_old = u.rhs;
-            u.g = f32::INFINITY;
-            u.rhs = u.key().1;
-
-            for predecessor in predecessors() {
-                if let Some(cost) = cost_compute(&nodes.view(), &predecessor, node) {
-                    let (x, y) = predecessor;
-                    let predecessor_node = nodes.get(x, y).unwrap();
-
-                    if predecessor_node == goal {
-                        continue;
-                    }
-
-                    if predecessor_node.rhs + cost == g_old {
-                        if let Some(new_cost) = cost_compute(&nodes.view(), &predecessor, &u) {
-                            rhs_old = rhs_old.min(predecessor_node.g + new_cost);
-                        }
-                    }
-                }
+            let QueueItem { key: k_old, node: u } = self.queue.pop().unwrap();
+            let k_new = self.calculate_key(&u);
+            if k_old < k_new {
+                self.queue.push(QueueItem { key: k_new, node: u });
+                continue;
             }
-
-            u.rhs = rhs_old;
-            if u.rhs != g_old {
-                u.set_open();
+            let g_u = self.g(&u);
+            let rhs_u = self.rhs(&u);
+            if g_u == rhs_u {
+                // `u` was already consistent when this entry was queued (e.g. it got resettled by
+                // a different pop before this one came up); nothing left to propagate.
+                continue;
             }
-
-            for neighbor in neighbors(node) {
-                if let Some(cost) = cost_compute(&nodes.view(), &u, &neighbor) {
-                    update_vertex(nodes, &neighbor, start, goal);
+            if g_u > rhs_u {
+                self.g.insert(u.clone(), rhs_u);
+                for (pred, _) in self.graph.predecessors(&u) {
+                    self.update_vertex(&pred);
+                }
+            } else {
+                self.g.remove(&u);
+                self.update_vertex(&u);
+                for (pred, _) in self.graph.predecessors(&u) {
+                    self.update_vertex(&pred);
                 }
             }
         }
-    } else if u.is_new() {
-        u.rhs = u.key().1;
-        u.set_open();
+    }
 
-        for neighbor in neighbors(node) {
-            if let Some(cost) = cost_compute(&nodes.view(), &u, &neighbor) {
-                update_vertex(nodes, &neighbor, start, goal);
-            }
+    /// Notify the planner that the edges touching `node` changed cost, so the next
+    /// [`compute_shortest_path`](Self::compute_shortest_path) call only propagates through the
+    /// affected region instead of recomputing the whole graph.
+    pub fn update_edge_cost(&mut self, node: &G::NodeId) {
+        self.update_vertex(node);
+        for (pred, _) in self.graph.predecessors(node) {
+            self.update_vertex(&pred);
         }
-    } else {
-        u.set_open();
+    }
+
+    /// Move the agent to `new_start`, bumping `k_m` so previously computed keys stay valid
+    /// without re-sorting the whole queue.
+    pub fn move_start(&mut self, new_start: G::NodeId) {
+        self.k_m = self.k_m + self.graph.heuristic(&self.last_start, &new_start);
+        self.last_start = new_start.clone();
+        self.start = new_start;
+    }
 
-        for neighbor in neighbors(node) {
-            if let Some(cost) = cost_compute(&nodes.view(), &u, &neighbor) {
-                update_vertex(nodes, &neighbor, start, goal);
+    /// Greedily follow, from `start`, the successor minimizing `edge_cost + g`, yielding the
+    /// current shortest path to the goal. Returns `None` if no such path exists yet.
+    pub fn path(&self) -> Option<Vec<G::NodeId>> {
+        let mut path = vec![self.start.clone()];
+        let mut current = self.start.clone();
+        while current != self.goal {
+            let (next, _) = self
+                .graph
+                .successors(&current)
+                .into_iter()
+                .min_by_key(|(n, c)| Self::add_cost(*c, self.g(n)))?;
+            if self.g(&next) == G::Cost::max_value() {
+                return None;
             }
+            path.push(next.clone());
+            current = next;
         }
+        Some(path)
     }
 }
-// The calculate_key function takes a reference to a Node struct and returns a tuple of two f64 values. The first value, k1, is calculated as the sum of the smaller of the g and rhs values for the node, and the heuristic estimate of the distance from the node to the goal. The second value, k2, is simply the smaller of the g and rhs values.
 
-// The returned tuple is used as the key for the priority queue, so that nodes with smaller keys are popped from the queue first. The choice of k1 and k2 for the key values ensures that nodes with smaller g and rhs values, and nodes closer to the goal, are prioritized by the algorithm.
+const ORTHOGONAL_COST: u32 = 100;
+const DIAGONAL_COST: u32 = 141; // 100 * sqrt(2), rounded, so `Cost` can stay an integer `Ord` type.
 
-fn calculate_key(node: &Node) -> (f64, f64) {
-    let k1 = f64::min(node.g, node.rhs) + heuristic(node, &node.goal);
-    let k2 = f64::min(node.g, node.rhs);
-    (k1, k2)
+/// A [`DStarGraph`] adapter over a dense boolean occupancy grid (`true` = obstacle), with
+/// 8-connected movement. This is the grid storage the D* Lite core used to be hard-wired to;
+/// now it is just one possible implementor of the trait.
+pub struct GridGraph {
+    map: Array2<bool>,
 }
 
-// we initialize a priority queue with a single state consisting of the start node, and then repeatedly pop the state with the smallest key value from the priority queue until we reach the goal node or the priority queue is empty. For each node popped from the priority queue, we update its g and rhs values using the update_vertex function, and then iterate over its neighbors to see if we can improve their values. If a neighbor's g or rhs value is updated, we push it onto the priority queue with a new key value computed using the calculate_key function.
-
-// Once we have found the optimal path from the start node to the goal node, we construct it by starting at the goal node and repeatedly moving to its neighbor with the smallest rhs value until we reach the start node. The path is then printed to the console.
+impl GridGraph {
+    pub fn new(map: Array2<bool>) -> Self {
+        Self { map }
+    }
 
-fn main() {
-    // create the initial search graph
-    let mut nodes = vec![
-        Node { x: 0, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 0, y: 1, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 0, y: 2, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 1, y: 1, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 1, y: 2, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 2, y: 2, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 3, y: 2, g: 0.0, rhs: 0.0 },
-        Node { x: 4, y: 2, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 5, y: 2, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 6, y: 2, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 7, y: 2, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 7, y: 1, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 7, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 6, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 5, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 4, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 3, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 2, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 1, y: 0, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-        Node { x: 1, y: 3, g: std::f64::INFINITY, rhs: std::f64::INFINITY },
-    ];
-
-    // set the start and goal nodes
-    let start = &mut nodes[1];
-    let goal = &mut nodes[18];
-    start.g = 0.0;
-    start.rhs = 0.0;
-
-    // initialize the priority queue
-    let mut queue = BinaryHeap::new();
-    queue.push(State { key: calculate_key(start), node: start });
-
-    // run the algorithm
-    while let Some(State { node, .. }) = queue.pop() {
-        if node == goal {
-            break;
+    fn in_bounds(&self, (x, y): (isize, isize)) -> Option<(usize, usize)> {
+        let (rows, cols) = self.map.dim();
+        if x >= 0 && y >= 0 && (x as usize) < rows && (y as usize) < cols {
+            Some((x as usize, y as usize))
+        } else {
+            None
         }
+    }
 
-        node.visited = true;
-
-        update_vertex(&mut nodes, &mut queue, node);
+    fn edge_cost(&self, a: (usize, usize), b: (usize, usize)) -> Option<u32> {
+        if self.map[b] {
+            return None;
+        }
+        let dx = (a.0 as isize - b.0 as isize).abs();
+        let dy = (a.1 as isize - b.1 as isize).abs();
+        Some(if dx + dy == 1 { ORTHOGONAL_COST } else { DIAGONAL_COST })
+    }
 
-        for neighbor in get_neighbors(&nodes, node) {
-            if !neighbor.visited {
-                let new_g = node.g + cost(&nodes, node, neighbor);
-                if new_g < neighbor.g {
-                    neighbor.rhs = new_g + heuristic(&neighbor, goal);
-                    neighbor.g = new_g;
-                    queue.push(State { key: calculate_key(&neighbor), node: neighbor });
-                } else if new_g < neighbor.rhs {
-                    neighbor.rhs = new_g;
-                    queue.push(State { key: calculate_key(&neighbor), node: neighbor });
+    fn around(&self, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+        let mut out = Vec::with_capacity(8);
+        for dx in -1isize..=1 {
+            for dy in -1isize..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                if let Some(n) = self.in_bounds((x as isize + dx, y as isize + dy)) {
+                    out.push(n);
                 }
             }
         }
+        out
     }
+}
 
-    // get the path
-    let mut path = vec![goal];
-    let mut current = goal;
-
-    while current != start {
-        let neighbors = get_neighbors(&nodes, current);
-        let mut min_rhs = std::f64::INFINITY;
-        let mut next_node = current;
-
-        for neighbor in neighbors {
-            let rhs = neighbor.g + cost(&nodes, neighbor, current);
-            if rhs < min_rhs {
-                min_rhs = rhs;
-                next_node = neighbor;
-            }
-        }
+impl DStarGraph for GridGraph {
+    type NodeId = (usize, usize);
+    type Cost = u32;
 
-        current = next_node;
-        path.push(current);
+    fn successors(&self, node: &Self::NodeId) -> Vec<(Self::NodeId, Self::Cost)> {
+        self.around(*node)
+            .into_iter()
+            .filter_map(|n| self.edge_cost(*node, n).map(|c| (n, c)))
+            .collect()
     }
 
-    path.reverse();
+    fn predecessors(&self, node: &Self::NodeId) -> Vec<(Self::NodeId, Self::Cost)> {
+        self.around(*node)
+            .into_iter()
+            .filter_map(|n| self.edge_cost(n, *node).map(|c| (n, c)))
+            .collect()
+    }
 
-    // print the path
-    println!("Optimal path:");
-    for node in &path {
-        println!("({}, {})", node.x, node.y);
+    fn heuristic(&self, a: &Self::NodeId, b: &Self::NodeId) -> Self::Cost {
+        // Octile distance with the same costs as `edge_cost`, so it never exceeds the real cost.
+        let dx = a.0.abs_diff(b.0) as u32;
+        let dy = a.1.abs_diff(b.1) as u32;
+        dx.min(dy) * DIAGONAL_COST + (dx.max(dy) - dx.min(dy)) * ORTHOGONAL_COST
     }
 }
+
+fn main() {
+    let map = Array2::from_elem((8, 8), false);
+    let start = (0, 0);
+    let goal = (7, 7);
+
+    let mut planner = DStarLite::new(GridGraph::new(map), start, goal);
+    planner.compute_shortest_path();
+    println!("Path: {:?}", planner.path());
+
+    // An obstacle appears in front of the agent; replan without starting over.
+    planner.graph.map[(3, 3)] = true;
+    planner.update_edge_cost(&(3, 3));
+    planner.compute_shortest_path();
+    println!("Path after obstacle: {:?}", planner.path());
+}